@@ -0,0 +1,27 @@
+use clap::{ArgMatches, Command};
+use anyhow::{Context, Result};
+use crate::lsp;
+
+pub fn spec() -> Command {
+    Command::new("lsp")
+        .about("Run the moli.yml language server over stdio")
+        .long_about(
+            "Starts a Language Server Protocol backend for moli.yml, so editors get \
+            live feedback while authoring it:\n\
+            \n\
+            • Diagnostics for unknown languages, duplicate project names, multiple \
+            'root: true' entries, and empty modules\n\
+            • Completion for language values and field keys (name/root/lang/tree/file/pub)\n\
+            • Hover text describing each field\n\
+            • A 'Preview generated tree' code action that dry-runs 'moli up'\n\
+            \n\
+            Configure your editor (VS Code, Neovim, ...) to launch 'moli lsp' over \
+            stdio for moli.yml files."
+        )
+}
+
+pub fn action(_matches: &ArgMatches) -> Result<()> {
+    let runtime = tokio::runtime::Runtime::new().context("Failed to start async runtime")?;
+    runtime.block_on(lsp::run());
+    Ok(())
+}
@@ -2,6 +2,8 @@ use clap::{Arg, ArgMatches, Command};
 use anyhow::{bail, Context, Result};
 use inquire::Select;
 use std::fs;
+use crate::project_management::config::ConfigParser;
+use crate::project_management::language::{DirectoryConvention, LanguageRegistry};
 
 pub fn spec() -> Command {
     Command::new("new")
@@ -20,7 +22,7 @@ pub fn spec() -> Command {
             • Auto-generates sequential project names (app_1, app_2, etc.)\n\
             • Smart multi-project handling (removes root: true from existing projects)\n\
             • Language-specific directory structures (Rust uses src/, others use root-level)\n\
-            • Supports: rust, go, python, typescript, javascript, any"
+            • Supports any language registered in the LanguageRegistry"
         )
         .arg(
             Arg::new("lang")
@@ -28,8 +30,9 @@ pub fn spec() -> Command {
                 .long("lang")
                 .help("Programming language for direct specification (AI mode)")
                 .long_help(
-                    "Specify the programming language directly without interactive prompts. \
-                    Supported languages: rust, go, python, typescript, javascript, any. \
+                    "Specify the programming language directly without interactive prompts, \
+                    by canonical name or alias registered in the LanguageRegistry (built-in \
+                    defaults plus ~/.config/moli/languages.yml). \
                     When omitted, enters interactive mode for human users."
                 )
                 .value_name("LANGUAGE")
@@ -38,20 +41,20 @@ pub fn spec() -> Command {
 }
 
 pub fn action(matches: &ArgMatches) -> Result<()> {
+    let registry = load_registry();
+
     let language = if let Some(lang) = matches.get_one::<String>("lang") {
         // AI mode - language specified via --lang option
-        let supported_languages = ["rust", "go", "python", "typescript", "javascript", "any"];
-        if !supported_languages.contains(&lang.as_str()) {
-            bail!("Unsupported language: {}. Supported languages: {}", lang, supported_languages.join(", "));
+        if !registry.is_known(lang) {
+            bail!("Unsupported language: {}. Supported languages: {}", lang, registry.names().join(", "));
         }
         lang.clone()
     } else {
         // Human mode - interactive language selection
-        let languages = vec!["rust", "go", "python", "typescript", "javascript", "any"];
-        Select::new("Programming language:", languages)
+        Select::new("Programming language:", registry.names())
             .prompt()
-            .context("Failed to get programming language")?.
-            to_string()
+            .context("Failed to get programming language")?
+            .to_string()
     };
 
     // Determine project name
@@ -66,11 +69,11 @@ pub fn action(matches: &ArgMatches) -> Result<()> {
     // Check if moli.yml already exists
     if fs::metadata("moli.yml").is_ok() {
         // Existing moli.yml - append new project
-        append_to_existing_moli_yml(&project_name, &language)?;
+        append_to_existing_moli_yml(&project_name, &language, &registry)?;
         println!("✓ Added {} ({}) project to existing moli.yml", project_name, language);
     } else {
         // No existing moli.yml - create new one with root: true
-        let moli_content = generate_new_moli_yml(&project_name, &language)?;
+        let moli_content = generate_new_moli_yml(&project_name, &language, &registry)?;
         fs::write("moli.yml", moli_content)
             .context("Failed to write moli.yml")?;
         println!("✓ Generated new moli.yml for {} ({}) project", project_name, language);
@@ -82,40 +85,30 @@ pub fn action(matches: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
-fn generate_new_moli_yml(project_name: &str, language: &str) -> Result<String> {
-    let main_file = get_main_file_name(language, true); // root: true
+/// Build the language registry, merging in any `languages:` an
+/// existing moli.yml registers inline -- so `--lang <custom>` can
+/// select a language a project only declared there, with no
+/// `~/.config/moli/languages.yml` entry. Best-effort: a moli.yml that
+/// doesn't parse yet (mid-edit, or about to be created by this very
+/// command) just falls back to the built-in registry instead of
+/// failing `moli new` outright.
+fn load_registry() -> LanguageRegistry {
+    let custom_languages = fs::metadata("moli.yml")
+        .is_ok()
+        .then(|| ConfigParser::parse_file("moli.yml").ok())
+        .flatten()
+        .map(|config| config.languages().to_vec())
+        .unwrap_or_default();
+
+    LanguageRegistry::load(&custom_languages)
+}
 
-    match language {
-        "rust" => {
-            // Rust standard: src/main.rs or src/lib.rs
-            Ok(format!(
-                r#"- name: {}
-  root: true
-  lang: {}
-  tree:
-    - name: src
-      file:
-        - name: {}
-"#,
-                project_name, language, main_file
-            ))
-        },
-        "go" => {
-            // Go standard: main.go at project root for simple projects
-            Ok(format!(
-                r#"- name: {}
-  root: true
-  lang: {}
-  file:
-    - name: {}
-"#,
-                project_name, language, main_file
-            ))
-        },
-        "python" | "typescript" | "javascript" => {
-            // Modern standard: src/ directory structure
-            Ok(format!(
-                r#"- name: {}
+fn generate_new_moli_yml(project_name: &str, language: &str, registry: &LanguageRegistry) -> Result<String> {
+    let main_file = main_file_name(language, true, registry);
+
+    Ok(match directory_convention(language, registry) {
+        DirectoryConvention::Tree => format!(
+            r#"- name: {}
   root: true
   lang: {}
   tree:
@@ -123,39 +116,21 @@ fn generate_new_moli_yml(project_name: &str, language: &str) -> Result<String> {
       file:
         - name: {}
 "#,
-                project_name, language, main_file
-            ))
-        },
-        "any" => {
-            // Any language: root-level files with specified extensions
-            Ok(format!(
-                r#"- name: {}
+            project_name, language, main_file
+        ),
+        DirectoryConvention::Root => format!(
+            r#"- name: {}
   root: true
   lang: {}
   file:
     - name: {}
 "#,
-                project_name, language, main_file
-            ))
-        },
-        _ => {
-            // Default: src/ directory structure
-            Ok(format!(
-                r#"- name: {}
-  root: true
-  lang: {}
-  tree:
-    - name: src
-      file:
-        - name: {}
-"#,
-                project_name, language, main_file
-            ))
-        }
-    }
+            project_name, language, main_file
+        ),
+    })
 }
 
-fn append_to_existing_moli_yml(project_name: &str, language: &str) -> Result<()> {
+fn append_to_existing_moli_yml(project_name: &str, language: &str, registry: &LanguageRegistry) -> Result<()> {
     // Read existing moli.yml
     let existing_content = fs::read_to_string("moli.yml")
         .context("Failed to read existing moli.yml")?;
@@ -164,40 +139,10 @@ fn append_to_existing_moli_yml(project_name: &str, language: &str) -> Result<()>
     let updated_content = replace_first_project_name_with_current_dir(&existing_content)?;
 
     // Generate new project YAML
-    let main_file = get_main_file_name(language, false); // not root: true
-    let new_project_yaml = match language {
-        "rust" => {
-            // Rust standard: src/main.rs or src/lib.rs
-            format!(
-                r#"
-
-- name: {}
-  lang: {}
-  tree:
-    - name: src
-      file:
-        - name: {}
-"#,
-                project_name, language, main_file
-            )
-        },
-        "go" => {
-            // Go standard: main.go at project root for simple projects
-            format!(
-                r#"
-
-- name: {}
-  lang: {}
-  file:
-    - name: {}
-"#,
-                project_name, language, main_file
-            )
-        },
-        "python" | "typescript" | "javascript" => {
-            // Modern standard: src/ directory structure
-            format!(
-                r#"
+    let main_file = main_file_name(language, false, registry);
+    let new_project_yaml = match directory_convention(language, registry) {
+        DirectoryConvention::Tree => format!(
+            r#"
 
 - name: {}
   lang: {}
@@ -206,37 +151,18 @@ fn append_to_existing_moli_yml(project_name: &str, language: &str) -> Result<()>
       file:
         - name: {}
 "#,
-                project_name, language, main_file
-            )
-        },
-        "any" => {
-            // Any language: root-level files with specified extensions
-            format!(
-                r#"
+            project_name, language, main_file
+        ),
+        DirectoryConvention::Root => format!(
+            r#"
 
 - name: {}
   lang: {}
   file:
     - name: {}
 "#,
-                project_name, language, main_file
-            )
-        },
-        _ => {
-            // Default: src/ directory structure
-            format!(
-                r#"
-
-- name: {}
-  lang: {}
-  tree:
-    - name: src
-      file:
-        - name: {}
-"#,
-                project_name, language, main_file
-            )
-        }
+            project_name, language, main_file
+        ),
     };
 
     // Combine and write back
@@ -287,14 +213,14 @@ fn replace_first_project_name_with_current_dir(content: &str) -> Result<String>
     Ok(result.to_string())
 }
 
-fn get_main_file_name(language: &str, is_root: bool) -> &str {
-    match language {
-        "rust" => if is_root { "main" } else { "lib" },
-        "go" => "main",
-        "python" => "main",
-        "typescript" => "index",
-        "javascript" => "index",
-        "any" => "README.md",
-        _ => "main",
-    }
+/// Look up `language`'s main file name for a root/non-root project,
+/// falling back to `"main"` for an unregistered language.
+fn main_file_name<'a>(language: &'a str, is_root: bool, registry: &'a LanguageRegistry) -> &'a str {
+    registry.get(language).map(|lang| lang.main_file_name(is_root)).unwrap_or("main")
+}
+
+/// Look up `language`'s directory convention, falling back to `Tree`
+/// (a `src/` layout) for an unregistered language.
+fn directory_convention(language: &str, registry: &LanguageRegistry) -> DirectoryConvention {
+    registry.get(language).map(|lang| lang.convention).unwrap_or(DirectoryConvention::Tree)
 }
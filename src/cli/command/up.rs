@@ -3,6 +3,7 @@ use anyhow::{bail, Context, Result};
 use std::fs;
 use std::path::Path;
 use crate::project_management::config::{ConfigParser, ConfigValidator};
+use crate::project_management::language::LanguageRegistry;
 use crate::code_generation::core::generator::CodeGenerator;
 
 pub fn spec() -> Command {
@@ -56,8 +57,13 @@ fn action_generate() -> Result<()> {
     let config = ConfigParser::parse_default()
         .context("Failed to parse moli.yml")?;
 
+    // Resolve the language registry from built-in defaults, the
+    // user's ~/.config/moli/languages.yml, and moli.yml's inline
+    // `languages:` section.
+    let registry = LanguageRegistry::load(config.languages());
+
     // Validate configuration
-    ConfigValidator::validate(&config)
+    ConfigValidator::validate(&config, &registry)
         .context("Configuration validation failed")?;
 
     // Print generating message for each project
@@ -66,7 +72,7 @@ fn action_generate() -> Result<()> {
     }
 
     // Generate structure using the new CodeGenerator
-    CodeGenerator::generate_from_config(".", &config)
+    CodeGenerator::generate_from_config(".", &config, &registry)
         .context("Failed to generate project structure")?;
 
     // Print success message for each project
@@ -0,0 +1,165 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use anyhow::{bail, Context, Result};
+use notify::{RecursiveMode, Watcher};
+use crate::project_management::config::{ConfigParser, ConfigValidator};
+use crate::project_management::config::models::MoliConfig;
+use crate::project_management::language::LanguageRegistry;
+use crate::code_generation::core::diff::ConfigDiff;
+use crate::code_generation::core::generator::CodeGenerator;
+
+/// How long to wait for further filesystem events after the first one
+/// before regenerating, so a single save doesn't trigger multiple runs.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub fn spec() -> Command {
+    Command::new("watch")
+        .about("Watch moli.yml and regenerate on change")
+        .long_about(
+            "Watch moli.yml for changes and incrementally regenerate the project \
+            structure, so editing the spec feels as immediate as 'moli up' without \
+            having to run it by hand.\n\
+            \n\
+            On each change:\n  \
+            • Re-parses and re-validates moli.yml\n  \
+            • Diffs the new configuration against the last generated one\n  \
+            • Creates only newly added modules/files\n  \
+            • Refreshes barrel marker-sections whose member set changed\n  \
+            • With --prune, removes files/directories deleted from the spec\n\
+            \n\
+            Press Ctrl+C to stop watching."
+        )
+        .arg(
+            Arg::new("prune")
+                .long("prune")
+                .help("Remove generated files for entries deleted from moli.yml")
+                .action(ArgAction::SetTrue)
+        )
+}
+
+pub fn action(matches: &ArgMatches) -> Result<()> {
+    if !ConfigParser::config_exists() {
+        bail!("moli.yml not found. Run 'moli new' to create a new project configuration.");
+    }
+
+    let prune = matches.get_flag("prune");
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .context("Failed to start filesystem watcher")?;
+
+    watcher
+        .watch(Path::new(ConfigParser::default_config_path()), RecursiveMode::NonRecursive)
+        .context("Failed to watch moli.yml")?;
+
+    println!("Watching moli.yml for changes (Ctrl+C to stop)...");
+
+    // Incremental state: the last config we generated from, so a diff
+    // can skip unchanged subtrees entirely on the next run.
+    let mut previous: Option<MoliConfig> = None;
+    regenerate(&mut previous, prune)?;
+
+    loop {
+        if rx.recv().is_err() {
+            break;
+        }
+        // Drain any further events within the debounce window so a
+        // burst of saves only triggers one regeneration.
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+
+        if let Err(err) = regenerate(&mut previous, prune) {
+            eprintln!("[watch] {err:#}");
+        }
+    }
+
+    Ok(())
+}
+
+fn regenerate(previous: &mut Option<MoliConfig>, prune: bool) -> Result<()> {
+    let config = ConfigParser::parse_default().context("Failed to parse moli.yml")?;
+    let registry = LanguageRegistry::load(config.languages());
+    ConfigValidator::validate(&config, &registry).context("Configuration validation failed")?;
+
+    let diff = ConfigDiff::compute(previous.as_ref(), &config, &registry);
+
+    if previous.is_some() && diff.is_empty() {
+        return Ok(());
+    }
+
+    // Only the directories the diff says changed are walked at all, so
+    // unrelated subtrees (and their barrel files) aren't touched on
+    // every trigger -- not just idempotently re-written.
+    CodeGenerator::generate_incremental(".", &config, &registry, &diff)
+        .context("Failed to generate project structure")?;
+
+    if prune {
+        for path in &diff.removed_files {
+            prune_path(previous.as_ref(), path)?;
+        }
+    }
+
+    print_summary(&diff, prune);
+
+    *previous = Some(config);
+    Ok(())
+}
+
+/// Remove a generated file (and any directories left empty by its
+/// removal, up to the project root) for an entry deleted from
+/// moli.yml. `path` is `<project>/<module path>/<filename.ext>`.
+fn prune_path(previous: Option<&MoliConfig>, path: &str) -> Result<()> {
+    let Some(previous) = previous else { return Ok(()) };
+
+    let mut segments = path.splitn(2, '/');
+    let Some(project_name) = segments.next() else { return Ok(()) };
+    let Some(rest) = segments.next() else { return Ok(()) };
+
+    let Some(project) = previous.projects().iter().find(|p| p.name() == project_name) else {
+        return Ok(());
+    };
+
+    let project_dir: PathBuf = if project.is_root() { PathBuf::from(".") } else { PathBuf::from(project_name) };
+    let file_path = project_dir.join(rest);
+
+    if file_path.exists() {
+        std::fs::remove_file(&file_path)
+            .with_context(|| format!("Failed to remove: {}", file_path.display()))?;
+    }
+
+    let mut dir = file_path.parent().map(Path::to_path_buf);
+    while let Some(current) = dir.filter(|d| d != &project_dir && d.starts_with(&project_dir)) {
+        if std::fs::read_dir(&current).map(|mut entries| entries.next().is_none()).unwrap_or(false) {
+            std::fs::remove_dir(&current).ok();
+            dir = current.parent().map(Path::to_path_buf);
+        } else {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_summary(diff: &ConfigDiff, prune: bool) {
+    if diff.is_empty() {
+        println!("[watch] moli.yml changed, nothing to regenerate");
+        return;
+    }
+
+    for path in &diff.added_files {
+        println!("  + {}", path);
+    }
+    for path in &diff.changed_dirs {
+        println!("  ~ {} (members changed)", path);
+    }
+    for path in &diff.removed_files {
+        if prune {
+            println!("  - {}", path);
+        } else {
+            println!("  - {} (removed from moli.yml, rerun with --prune to delete)", path);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use dashmap::DashMap;
+use crate::project_management::language::LanguageRegistry;
+use crate::code_generation::core::diff::ConfigDiff;
+
+/// Per-path locks guarding concurrent barrel-file (marker section)
+/// rewrites, so sibling module subtrees generating in parallel never
+/// race when two of them happen to target the same barrel file.
+pub type DirLocks = Arc<DashMap<PathBuf, Arc<Mutex<()>>>>;
+
+/// Directories (`<project>[/<module path>]`) that actually need file
+/// creation or a barrel refresh for an incremental `moli watch` run,
+/// derived from a `ConfigDiff`. `DirectoryBuilder` consults this to
+/// skip subtrees with no added files and no changed child set entirely,
+/// instead of walking and idempotently re-writing every barrel file in
+/// the tree on every trigger.
+#[derive(Debug, Default)]
+pub struct TouchSet {
+    dirs: HashSet<String>,
+}
+
+impl TouchSet {
+    /// Build the set of directories touched by `diff`: every directory
+    /// `ConfigDiff::compute` flags as changed (including brand new
+    /// ones, and by extension every ancestor of a wholly new subtree,
+    /// since each of those is equally new), plus the parent directory
+    /// of every added file -- the latter is what catches a project's
+    /// own root-level files, which aren't tracked as a "directory" by
+    /// `ConfigDiff` at all.
+    pub fn from_diff(diff: &ConfigDiff) -> Self {
+        let mut dirs: HashSet<String> = diff.changed_dirs.iter().cloned().collect();
+
+        for file in &diff.added_files {
+            if let Some((dir, _)) = file.rsplit_once('/') {
+                dirs.insert(dir.to_string());
+            }
+        }
+
+        TouchSet { dirs }
+    }
+
+    /// Whether `path` itself needs its files/barrel rebuilt.
+    pub fn touches(&self, path: &str) -> bool {
+        self.dirs.contains(path)
+    }
+
+    /// Whether `path` or anything nested under it needs work, so a
+    /// directory with no direct changes but a changed descendant is
+    /// still walked (without doing any work at this level itself).
+    pub fn touches_subtree(&self, path: &str) -> bool {
+        if self.touches(path) {
+            return true;
+        }
+        let prefix = format!("{}/", path);
+        self.dirs.iter().any(|dir| dir.starts_with(&prefix))
+    }
+}
+
+/// Read-only context threaded through a single generation run, bundling
+/// what `FileBuilder`/`DirectoryBuilder` need about the project being
+/// built without repeating `language`/`project_name`/`registry` as
+/// separate parameters at every call site. Shared across the rayon
+/// tasks spawned for a project's independent sibling subtrees.
+pub struct GenerationContext<'a> {
+    pub language: &'a str,
+    pub project_name: &'a str,
+    pub registry: &'a LanguageRegistry,
+    pub dir_locks: DirLocks,
+    /// `None` for a full `moli up`/`moli new` generation, where every
+    /// directory is touched; `Some` for `moli watch`'s incremental
+    /// runs, where only the directories in the set are built.
+    pub touch: Option<Arc<TouchSet>>,
+}
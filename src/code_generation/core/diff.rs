@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+use crate::project_management::config::models::{Module, MoliConfig};
+use crate::project_management::language::LanguageRegistry;
+
+/// The set of child file/module names under a directory, used to
+/// detect when a barrel file's managed section needs refreshing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DirSignature {
+    file_names: Vec<String>,
+    child_names: Vec<String>,
+}
+
+/// The delta between two generations of a `MoliConfig`, as used by
+/// `moli watch` to apply only what changed and print a concise
+/// per-change summary.
+#[derive(Debug, Default)]
+pub struct ConfigDiff {
+    /// On-disk file paths present only in the new config.
+    pub added_files: Vec<String>,
+    /// On-disk file paths present only in the previous config; only
+    /// acted on when `--prune` is set.
+    pub removed_files: Vec<String>,
+    /// Module directory paths (`<project>/<module path>`) whose set of
+    /// child modules or files changed since the last generation, or
+    /// that are new. A project's own root directory is never listed.
+    pub changed_dirs: Vec<String>,
+}
+
+impl ConfigDiff {
+    /// Diff `previous` (the last generated config, if any) against
+    /// `current`. Paths are `<project>/<module path>/<filename.ext>`.
+    pub fn compute(previous: Option<&MoliConfig>, current: &MoliConfig, registry: &LanguageRegistry) -> Self {
+        let previous_files = previous.map(|config| Self::collect_files(config, registry)).unwrap_or_default();
+        let current_files = Self::collect_files(current, registry);
+
+        let previous_dirs = previous.map(Self::collect_dirs).unwrap_or_default();
+        let current_dirs = Self::collect_dirs(current);
+
+        let mut added_files: Vec<String> = current_files.difference(&previous_files).cloned().collect();
+        let mut removed_files: Vec<String> = previous_files.difference(&current_files).cloned().collect();
+
+        // A directory counts as changed both when its signature differs
+        // from before and when it's brand new (absent from
+        // `previous_dirs` entirely) -- the latter matters just as much,
+        // since a wholly new subtree's directories (and their ancestors,
+        // themselves equally new) all need their barrel files created.
+        let mut changed_dirs: Vec<String> = current_dirs
+            .iter()
+            .filter(|(path, sig)| previous_dirs.get(*path).map_or(true, |prev| prev != *sig))
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        added_files.sort();
+        removed_files.sort();
+        changed_dirs.sort();
+
+        ConfigDiff { added_files, removed_files, changed_dirs }
+    }
+
+    /// Whether nothing changed between the two configs.
+    pub fn is_empty(&self) -> bool {
+        self.added_files.is_empty() && self.removed_files.is_empty() && self.changed_dirs.is_empty()
+    }
+
+    /// The full would-be file list for `config`, without touching
+    /// disk. Used by `moli lsp`'s "preview generated tree" action.
+    pub fn preview(config: &MoliConfig, registry: &LanguageRegistry) -> Vec<String> {
+        let mut files: Vec<String> = Self::collect_files(config, registry).into_iter().collect();
+        files.sort();
+        files
+    }
+
+    fn collect_files(config: &MoliConfig, registry: &LanguageRegistry) -> HashSet<String> {
+        let mut paths = HashSet::new();
+        for project in config.projects() {
+            for file in project.files() {
+                paths.insert(format!(
+                    "{}/{}",
+                    project.name(),
+                    file.filename_with_extension(registry, project.language())
+                ));
+            }
+            for module in project.spec() {
+                Self::collect_module_files(project.name(), project.language(), module, "", registry, &mut paths);
+            }
+        }
+        paths
+    }
+
+    fn collect_module_files(
+        project_name: &str,
+        language: &str,
+        module: &Module,
+        parent_path: &str,
+        registry: &LanguageRegistry,
+        paths: &mut HashSet<String>,
+    ) {
+        let module_path = Self::join(parent_path, module.name());
+        for file in module.files() {
+            paths.insert(format!(
+                "{}/{}/{}",
+                project_name,
+                module_path,
+                file.filename_with_extension(registry, language)
+            ));
+        }
+        for child in module.subtree() {
+            Self::collect_module_files(project_name, language, child, &module_path, registry, paths);
+        }
+    }
+
+    fn collect_dirs(config: &MoliConfig) -> HashMap<String, DirSignature> {
+        let mut dirs = HashMap::new();
+        for project in config.projects() {
+            for module in project.spec() {
+                Self::collect_module_dirs(project.name(), module, "", &mut dirs);
+            }
+        }
+        dirs
+    }
+
+    fn collect_module_dirs(
+        project_name: &str,
+        module: &Module,
+        parent_path: &str,
+        dirs: &mut HashMap<String, DirSignature>,
+    ) {
+        let module_path = Self::join(parent_path, module.name());
+
+        let mut file_names: Vec<String> = module.files().iter().map(|f| f.name().to_string()).collect();
+        file_names.sort();
+        let mut child_names: Vec<String> = module.subtree().iter().map(|m| m.name().to_string()).collect();
+        child_names.sort();
+
+        dirs.insert(format!("{}/{}", project_name, module_path), DirSignature { file_names, child_names });
+
+        for child in module.subtree() {
+            Self::collect_module_dirs(project_name, child, &module_path, dirs);
+        }
+    }
+
+    fn join(parent_path: &str, name: &str) -> String {
+        if parent_path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", parent_path, name)
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use anyhow::{Context, Result};
+use rayon::prelude::*;
+use crate::project_management::config::models::Module;
+use crate::code_generation::core::context::GenerationContext;
+use crate::code_generation::core::file_builder::FileBuilder;
+
+/// Recursively builds a `Module` tree: creates directories, delegates
+/// file creation to [`FileBuilder`], and keeps each directory's barrel
+/// file's managed marker section in sync with its child modules.
+///
+/// Independent sibling subtrees are built concurrently via rayon,
+/// since directory/file creation for disjoint paths shares no state;
+/// the only serialization points are `create_dir_all` (idempotent) and
+/// barrel-file rewrites, which take a per-path lock from
+/// `ctx.dir_locks`.
+pub struct DirectoryBuilder;
+
+impl DirectoryBuilder {
+    /// Build `module` under `parent_dir`.
+    ///
+    /// `parent_path` is the module path (slash-separated, relative to
+    /// the project root) of `parent_dir`, used to build the path seen
+    /// by templates; `inherited_vars` carries the project's and
+    /// ancestor modules' `vars`, to be merged with `module.vars()`.
+    pub fn build(
+        parent_dir: &Path,
+        module: &Module,
+        ctx: &GenerationContext,
+        parent_path: &str,
+        inherited_vars: &HashMap<String, String>,
+    ) -> Result<()> {
+        let module_path = if parent_path.is_empty() {
+            module.name().to_string()
+        } else {
+            format!("{}/{}", parent_path, module.name())
+        };
+        let full_path = format!("{}/{}", ctx.project_name, module_path);
+
+        // On an incremental `moli watch` run, skip this directory (and
+        // everything under it) entirely when the diff says neither it
+        // nor any descendant changed -- no mkdir, no file attempts, no
+        // barrel rewrite.
+        if let Some(touch) = &ctx.touch {
+            if !touch.touches_subtree(&full_path) {
+                return Ok(());
+            }
+        }
+
+        let dir = parent_dir.join(module.name());
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+
+        let mut vars = inherited_vars.clone();
+        vars.extend(module.vars().clone());
+
+        let touches_here = ctx.touch.as_ref().map_or(true, |touch| touch.touches(&full_path));
+
+        if touches_here {
+            module
+                .files()
+                .par_iter()
+                .try_for_each(|file| FileBuilder::build(&dir, file, ctx, &module_path, &vars))?;
+        }
+
+        module
+            .subtree()
+            .par_iter()
+            .try_for_each(|child| DirectoryBuilder::build(&dir, child, ctx, &module_path, &vars))?;
+
+        if touches_here {
+            Self::update_barrel(&dir, module, ctx)?;
+        }
+
+        Ok(())
+    }
+
+    /// Refresh the moli-managed marker section of this directory's
+    /// barrel file so it exports every child module.
+    fn update_barrel(dir: &Path, module: &Module, ctx: &GenerationContext) -> Result<()> {
+        let Some(lang) = ctx.registry.get(ctx.language) else { return Ok(()) };
+        let Some(barrel_name) = lang.barrel_file.as_deref() else { return Ok(()) };
+
+        let barrel_path = dir.join(barrel_name);
+
+        let lock: Arc<Mutex<()>> = ctx
+            .dir_locks
+            .entry(barrel_path.clone())
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone();
+        let _guard = lock.lock().unwrap();
+
+        let existing = fs::read_to_string(&barrel_path).unwrap_or_default();
+
+        let marker_start = lang.marker_start();
+        let marker_end = lang.marker_end();
+
+        let managed_lines: Vec<String> =
+            module.subtree().iter().map(|child| lang.export_line(child.name())).collect();
+
+        let managed_section = format!("{}\n{}\n{}", marker_start, managed_lines.join("\n"), marker_end);
+
+        let new_content = if let (Some(start), Some(end)) =
+            (existing.find(&marker_start), existing.find(&marker_end))
+        {
+            let mut content = existing.clone();
+            content.replace_range(start..end + marker_end.len(), &managed_section);
+            content
+        } else if existing.is_empty() {
+            managed_section
+        } else {
+            format!("{}\n\n{}", existing, managed_section)
+        };
+
+        fs::write(&barrel_path, new_content)
+            .with_context(|| format!("Failed to update barrel file: {}", barrel_path.display()))?;
+
+        Ok(())
+    }
+}
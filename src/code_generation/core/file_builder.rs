@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::CodeFile;
+use crate::code_generation::core::context::GenerationContext;
+use crate::code_generation::core::template_engine::{RenderContext, TemplateEngine};
+
+/// Builds individual code files, respecting moli's "code files are
+/// never overwritten" guarantee.
+pub struct FileBuilder;
+
+impl FileBuilder {
+    /// Create a code file under `dir` if it does not already exist.
+    ///
+    /// Starter content is rendered through [`TemplateEngine`] using
+    /// `inherited_vars` (the project's and ancestor modules' `vars`)
+    /// merged with the file's own `vars`, which take precedence.
+    /// Managed filenames (per `ctx.registry`) are created empty.
+    /// `DirectoryBuilder` maintains the marker section of the
+    /// language's `barrel_file` only; other managed files (e.g. Rust's
+    /// `main.rs`/`lib.rs`) stay as created.
+    pub fn build(
+        dir: &Path,
+        file: &CodeFile,
+        ctx: &GenerationContext,
+        module_path: &str,
+        inherited_vars: &HashMap<String, String>,
+    ) -> Result<()> {
+        let filename = file.filename_with_extension(ctx.registry, ctx.language);
+        let path = dir.join(&filename);
+
+        if path.exists() {
+            return Ok(());
+        }
+
+        let is_managed = ctx
+            .registry
+            .get(ctx.language)
+            .is_some_and(|lang| lang.is_managed(&filename));
+
+        if is_managed {
+            fs::write(&path, "")
+                .with_context(|| format!("Failed to create file: {}", path.display()))?;
+            return Ok(());
+        }
+
+        let mut vars = inherited_vars.clone();
+        vars.extend(file.vars().clone());
+
+        let render_ctx = RenderContext {
+            project_name: ctx.project_name,
+            lang: ctx.language,
+            module_path,
+            filename_with_extension: &filename,
+            pub_setting: file.pub_setting(),
+            vars: &vars,
+        };
+
+        let content = TemplateEngine::render(file.template(), &render_ctx)?;
+
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to create file: {}", path.display()))?;
+
+        Ok(())
+    }
+}
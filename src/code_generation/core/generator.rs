@@ -0,0 +1,211 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use anyhow::{Context, Result};
+use dashmap::DashMap;
+use rayon::prelude::*;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::project_management::language::LanguageRegistry;
+use crate::code_generation::core::context::{DirLocks, GenerationContext, TouchSet};
+use crate::code_generation::core::diff::ConfigDiff;
+use crate::code_generation::core::directory_builder::DirectoryBuilder;
+use crate::code_generation::core::file_builder::FileBuilder;
+use crate::code_generation::core::manifest::ManifestWriter;
+
+/// Top-level entry point for turning a parsed `MoliConfig` into an
+/// on-disk project structure.
+pub struct CodeGenerator;
+
+impl CodeGenerator {
+    /// Generate every project declared in `config` under `base_path`,
+    /// resolving languages against `registry`.
+    ///
+    /// Projects are independent (each owns its own directory and
+    /// manifest file), so they're generated concurrently via rayon;
+    /// the `dir_locks` map is shared across all of them so that two
+    /// projects can never race on the same barrel file.
+    pub fn generate_from_config(base_path: &str, config: &MoliConfig, registry: &LanguageRegistry) -> Result<()> {
+        Self::generate(base_path, config, registry, None)
+    }
+
+    /// Generate only the directories `diff` says changed, so `moli
+    /// watch` skips unrelated subtrees entirely instead of walking the
+    /// whole tree and idempotently re-writing every barrel file.
+    pub fn generate_incremental(
+        base_path: &str,
+        config: &MoliConfig,
+        registry: &LanguageRegistry,
+        diff: &ConfigDiff,
+    ) -> Result<()> {
+        Self::generate(base_path, config, registry, Some(Arc::new(TouchSet::from_diff(diff))))
+    }
+
+    fn generate(
+        base_path: &str,
+        config: &MoliConfig,
+        registry: &LanguageRegistry,
+        touch: Option<Arc<TouchSet>>,
+    ) -> Result<()> {
+        let base = Path::new(base_path);
+        let dir_locks: DirLocks = Arc::new(DashMap::new());
+
+        // Non-root Rust projects become workspace members of the root
+        // project's Cargo.toml, refreshed on every run.
+        let rust_workspace_members: Vec<String> = config
+            .sub_projects()
+            .iter()
+            .filter(|p| p.language() == "rust")
+            .map(|p| p.name().to_string())
+            .collect();
+
+        config.projects().par_iter().try_for_each(|project| {
+            let project_dir = if project.is_root() {
+                base.to_path_buf()
+            } else {
+                base.join(project.name())
+            };
+
+            fs::create_dir_all(&project_dir).with_context(|| {
+                format!("Failed to create project directory: {}", project_dir.display())
+            })?;
+
+            let workspace_members = (project.is_root()
+                && project.language() == "rust"
+                && !rust_workspace_members.is_empty())
+            .then_some(rust_workspace_members.as_slice());
+
+            Self::generate_project(&project_dir, project, registry, dir_locks.clone(), touch.clone(), workspace_members)
+        })?;
+
+        Ok(())
+    }
+
+    fn generate_project(
+        project_dir: &Path,
+        project: &Project,
+        registry: &LanguageRegistry,
+        dir_locks: DirLocks,
+        touch: Option<Arc<TouchSet>>,
+        workspace_members: Option<&[String]>,
+    ) -> Result<()> {
+        let ctx = GenerationContext {
+            language: project.language(),
+            project_name: project.name(),
+            registry,
+            dir_locks,
+            touch,
+        };
+
+        let touches_root = ctx.touch.as_ref().map_or(true, |touch| touch.touches(ctx.project_name));
+
+        if touches_root {
+            project
+                .files()
+                .par_iter()
+                .try_for_each(|file| FileBuilder::build(project_dir, file, &ctx, "", project.vars()))?;
+        }
+
+        project
+            .spec()
+            .par_iter()
+            .try_for_each(|module| DirectoryBuilder::build(project_dir, module, &ctx, "", project.vars()))?;
+
+        Self::sync_manifest(project_dir, project, registry, workspace_members)?;
+
+        Ok(())
+    }
+
+    /// Synthesize the language-specific project manifest, merging
+    /// `project.deps()` into the moli-owned subsection without
+    /// disturbing anything the user has hand-edited.
+    ///
+    /// Resolves `project.language()` through `registry` first (rather
+    /// than matching the raw string) so a project declared with an
+    /// alias -- `lang: py`/`ts`/`js` -- still gets its manifest synced
+    /// instead of silently falling through to the no-op arm.
+    fn sync_manifest(
+        project_dir: &Path,
+        project: &Project,
+        registry: &LanguageRegistry,
+        workspace_members: Option<&[String]>,
+    ) -> Result<()> {
+        let canonical = registry.get(project.language()).map(|lang| lang.name.as_str()).unwrap_or(project.language());
+
+        match canonical {
+            "rust" => ManifestWriter::sync_cargo_toml(
+                project_dir,
+                project.name(),
+                project.deps(),
+                workspace_members,
+            ),
+            "go" => ManifestWriter::sync_go_mod(project_dir, project.name(), project.deps()),
+            "javascript" | "typescript" => {
+                ManifestWriter::sync_package_json(project_dir, project.name(), project.deps())
+            }
+            "python" => ManifestWriter::sync_pyproject_toml(project_dir, project.name(), project.deps()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use crate::project_management::config::ConfigParser;
+
+    /// A fresh scratch directory, removed on drop.
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(label: &str) -> Self {
+            let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+            let dir = std::env::temp_dir().join(format!("moli-generator-test-{}-{}", label, nanos));
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// A project declared with a language alias (`py`/`ts`/`js`
+    /// instead of the canonical `python`/`typescript`/`javascript`)
+    /// must still get its manifest synced -- `sync_manifest` used to
+    /// match `project.language()` literally, so aliased projects fell
+    /// through to the no-op arm and their `deps:` were silently
+    /// dropped.
+    fn assert_manifest_synced_for_alias(lang: &str, manifest_file: &str, dep_name: &str) {
+        let yaml_content = format!(
+            "- name: app\n  root: true\n  lang: {lang}\n  deps:\n    - name: {dep_name}\n  spec:\n    - name: src\n      file:\n        - name: main\n"
+        );
+
+        let config = ConfigParser::parse_string(&yaml_content).unwrap();
+        let registry = LanguageRegistry::load(config.languages());
+        let dir = TempDir::new(lang);
+
+        CodeGenerator::generate_from_config(dir.0.to_str().unwrap(), &config, &registry).unwrap();
+
+        let manifest = fs::read_to_string(dir.0.join(manifest_file))
+            .unwrap_or_else(|_| panic!("{} should have been synced for lang: {}", manifest_file, lang));
+        assert!(manifest.contains(dep_name), "{} missing from {}: {}", dep_name, manifest_file, manifest);
+    }
+
+    #[test]
+    fn test_sync_manifest_resolves_python_alias() {
+        assert_manifest_synced_for_alias("py", "pyproject.toml", "requests");
+    }
+
+    #[test]
+    fn test_sync_manifest_resolves_typescript_alias() {
+        assert_manifest_synced_for_alias("ts", "package.json", "zod");
+    }
+
+    #[test]
+    fn test_sync_manifest_resolves_javascript_alias() {
+        assert_manifest_synced_for_alias("js", "package.json", "lodash");
+    }
+}
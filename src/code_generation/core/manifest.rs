@@ -0,0 +1,535 @@
+use std::fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use toml_edit::{value, Array, DocumentMut, InlineTable, Item, Table, Value};
+use crate::project_management::config::models::Dependency;
+
+/// Marker comments delimiting the moli-owned subsection of a manifest,
+/// mirroring the barrel-file marker philosophy but for structured
+/// config: only the wrapped entries are rewritten by `moli up`, so
+/// hand-added profiles, build settings, and other sections survive
+/// re-runs. Every dependency declared in `moli.yml` is considered
+/// moli-managed, so the wrapped region is fully replaced (including
+/// removals) on every sync. Cargo.toml, go.mod and pyproject.toml carry
+/// these markers; package.json has no comment syntax, so it carries
+/// none and its whole `"dependencies"` object is the moli-owned part.
+const MARKER_START: &str = "start auto exported by moli.";
+const MARKER_END: &str = "end auto exported by moli.";
+
+/// Synthesizes and idempotently merges `moli.yml` dependencies into
+/// each language's manifest format.
+pub struct ManifestWriter;
+
+impl ManifestWriter {
+    /// Create or update `Cargo.toml` under `project_dir`, optionally
+    /// refreshing its `[workspace] members` list.
+    pub fn sync_cargo_toml(
+        project_dir: &Path,
+        package_name: &str,
+        deps: &[Dependency],
+        workspace_members: Option<&[String]>,
+    ) -> Result<()> {
+        let path = project_dir.join("Cargo.toml");
+        let mut doc = Self::read_or_init_toml(&path, |doc| {
+            let mut package = Table::new();
+            package["name"] = value(package_name);
+            package["version"] = value("0.1.0");
+            package["edition"] = value("2021");
+            doc["package"] = Item::Table(package);
+        })?;
+
+        Self::sync_dep_table(&mut doc, "dependencies", deps.iter().filter(|d| !d.dev))?;
+        Self::sync_dep_table(&mut doc, "dev-dependencies", deps.iter().filter(|d| d.dev))?;
+
+        if let Some(members) = workspace_members {
+            let workspace = doc.entry("workspace").or_insert(Item::Table(Table::new()));
+            if let Some(table) = workspace.as_table_mut() {
+                let mut arr = Array::new();
+                for member in members {
+                    arr.push(member.as_str());
+                }
+                table["members"] = Item::Value(Value::Array(arr));
+            }
+        }
+
+        let start_marker = format!("# {}", MARKER_START);
+        let end_marker = format!("# {}", MARKER_END);
+        let content = Self::wrap_toml_tables(
+            &doc.to_string(),
+            &["[dependencies]", "[dev-dependencies]"],
+            &start_marker,
+            &end_marker,
+        );
+
+        Self::write(&path, &content)
+    }
+
+    /// Create or update `package.json`'s `dependencies` under
+    /// `project_dir`, preserving key order and untouched sections.
+    ///
+    /// Unlike the TOML manifests, this doesn't round-trip the whole
+    /// file through a parsed JSON value (`serde_json::Map` defaults to
+    /// alphabetical key order without the `preserve_order` feature,
+    /// which nothing in this crate enables) -- it patches the
+    /// `"dependencies"` object literal in place, leaving every other
+    /// byte of the file untouched.
+    pub fn sync_package_json(project_dir: &Path, package_name: &str, deps: &[Dependency]) -> Result<()> {
+        let path = project_dir.join("package.json");
+
+        let existing = if path.exists() {
+            fs::read_to_string(&path).with_context(|| format!("Failed to read manifest: {}", path.display()))?
+        } else {
+            format!("{{\n  \"name\": \"{}\",\n  \"version\": \"0.1.0\"\n}}\n", package_name)
+        };
+
+        let deps_block = Self::render_json_deps(deps);
+        let content = Self::replace_json_object(&existing, "dependencies", &deps_block)?;
+
+        Self::write(&path, &content)
+    }
+
+    /// Create or update `go.mod`'s moli-managed `require (...)` block
+    /// under `project_dir`.
+    pub fn sync_go_mod(project_dir: &Path, module_name: &str, deps: &[Dependency]) -> Result<()> {
+        let path = project_dir.join("go.mod");
+
+        let existing = if path.exists() {
+            fs::read_to_string(&path).with_context(|| format!("Failed to read manifest: {}", path.display()))?
+        } else {
+            format!("module {}\n\ngo 1.21\n", module_name)
+        };
+
+        let managed: Vec<String> = deps
+            .iter()
+            .map(|dep| format!("\t{} {}", dep.name(), dep.version().unwrap_or("v0.0.0")))
+            .collect();
+        let block = format!("require (\n{}\n)", managed.join("\n"));
+
+        let start_marker = format!("// {}", MARKER_START);
+        let end_marker = format!("// {}", MARKER_END);
+        let content = Self::replace_marked_block(&existing, &start_marker, &end_marker, &block);
+
+        Self::write(&path, &content)
+    }
+
+    /// Create or update `pyproject.toml`'s `[project.dependencies]`
+    /// list under `project_dir`.
+    pub fn sync_pyproject_toml(project_dir: &Path, package_name: &str, deps: &[Dependency]) -> Result<()> {
+        let path = project_dir.join("pyproject.toml");
+        let mut doc = Self::read_or_init_toml(&path, |doc| {
+            let mut project = Table::new();
+            project["name"] = value(package_name);
+            project["version"] = value("0.1.0");
+            doc["project"] = Item::Table(project);
+        })?;
+
+        let project = doc
+            .entry("project")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .context("[project] must be a table")?;
+
+        let mut arr = Array::new();
+        for dep in deps {
+            let spec = match dep.version() {
+                Some(version) => format!("{}{}", dep.name(), version),
+                None => dep.name().to_string(),
+            };
+            arr.push(spec);
+        }
+        project["dependencies"] = Item::Value(Value::Array(arr));
+
+        let start_marker = format!("# {}", MARKER_START);
+        let end_marker = format!("# {}", MARKER_END);
+        let content = Self::wrap_toml_line(&doc.to_string(), "dependencies = ", &start_marker, &end_marker);
+
+        Self::write(&path, &content)
+    }
+
+    fn read_or_init_toml(path: &Path, init: impl FnOnce(&mut DocumentMut)) -> Result<DocumentMut> {
+        if path.exists() {
+            fs::read_to_string(path)
+                .with_context(|| format!("Failed to read manifest: {}", path.display()))?
+                .parse::<DocumentMut>()
+                .with_context(|| format!("Failed to parse manifest: {}", path.display()))
+        } else {
+            let mut doc = DocumentMut::new();
+            init(&mut doc);
+            Ok(doc)
+        }
+    }
+
+    /// Replace `key`'s table wholesale with `deps`, so a dependency
+    /// removed from `moli.yml` is pruned on the next sync instead of
+    /// lingering forever.
+    fn sync_dep_table<'a>(
+        doc: &mut DocumentMut,
+        key: &str,
+        deps: impl Iterator<Item = &'a Dependency>,
+    ) -> Result<()> {
+        let table = doc
+            .entry(key)
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .with_context(|| format!("[{}] must be a table", key))?;
+        table.clear();
+
+        for dep in deps {
+            let req = dep.version().unwrap_or("*").to_string();
+            if dep.features.is_empty() {
+                table[dep.name()] = value(req);
+            } else {
+                let mut inline = InlineTable::new();
+                inline.insert("version", req.into());
+                let mut features = Array::new();
+                for feature in &dep.features {
+                    features.push(feature.as_str());
+                }
+                inline.insert("features", Value::Array(features));
+                table[dep.name()] = Item::Value(Value::InlineTable(inline));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render the `{ "name": "version", ... }` object literal for
+    /// `package.json`'s `dependencies` key.
+    fn render_json_deps(deps: &[Dependency]) -> String {
+        if deps.is_empty() {
+            return "{}".to_string();
+        }
+
+        let entries: Vec<String> = deps
+            .iter()
+            .map(|dep| format!("    \"{}\": \"{}\"", dep.name(), dep.version().unwrap_or("*")))
+            .collect();
+
+        format!("{{\n{}\n  }}", entries.join(",\n"))
+    }
+
+    /// Replace (or insert) the `"<key>": { ... }` object literal for
+    /// `key` inside raw JSON text by locating its matching braces,
+    /// leaving every other byte of `existing` untouched.
+    fn replace_json_object(existing: &str, key: &str, new_value: &str) -> Result<String> {
+        let needle = format!("\"{}\"", key);
+
+        if let Some(key_pos) = Self::find_top_level_key(existing, &needle) {
+            let after_key = &existing[key_pos + needle.len()..];
+            let brace_offset = after_key
+                .find('{')
+                .with_context(|| format!("\"{}\" is not a JSON object in package.json", key))?;
+            let brace_start = key_pos + needle.len() + brace_offset;
+
+            let end = Self::matching_brace_end(existing.as_bytes(), brace_start)
+                .with_context(|| format!("Unterminated \"{}\" object in package.json", key))?;
+
+            Ok(format!("{}{}{}", &existing[..brace_start], new_value, &existing[end..]))
+        } else {
+            let trimmed_end = existing.trim_end();
+            let root_end = trimmed_end
+                .rfind('}')
+                .with_context(|| "package.json is missing its root object")?;
+            let before = &trimmed_end[..root_end];
+
+            let needs_comma = !before.trim_end().ends_with('{');
+            let comma = if needs_comma { "," } else { "" };
+
+            Ok(format!("{}{}\n  \"{}\": {}\n}}\n", before, comma, key, new_value))
+        }
+    }
+
+    /// Find `needle` (a quoted key, e.g. `"dependencies"`) as a key of
+    /// the root object only. Skips over string contents while scanning
+    /// (so the key text appearing inside some unrelated string, e.g. a
+    /// `"keywords"` array entry, is never mistaken for the real key)
+    /// and only matches at brace depth 1 -- directly inside the root
+    /// object, not nested inside some other key's array/object value.
+    fn find_top_level_key(existing: &str, needle: &str) -> Option<usize> {
+        let bytes = existing.as_bytes();
+        let mut depth = 0i32;
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'"' => {
+                    let str_end = Self::skip_json_string(bytes, i);
+                    if depth == 1
+                        && &existing[i..str_end] == needle
+                        && existing[str_end..].trim_start().starts_with(':')
+                    {
+                        return Some(i);
+                    }
+                    i = str_end;
+                }
+                b'{' | b'[' => {
+                    depth += 1;
+                    i += 1;
+                }
+                b'}' | b']' => {
+                    depth -= 1;
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+        }
+        None
+    }
+
+    /// Byte offset just past the closing `"` of the JSON string that
+    /// starts at `quote_start` (which must point at the opening `"`),
+    /// honoring backslash escapes so an escaped quote doesn't end the
+    /// string early.
+    fn skip_json_string(bytes: &[u8], quote_start: usize) -> usize {
+        let mut i = quote_start + 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'"' => return i + 1,
+                _ => i += 1,
+            }
+        }
+        bytes.len()
+    }
+
+    /// Byte offset just past the `}` matching the `{` at `brace_start`,
+    /// skipping over string contents so a literal brace inside a value
+    /// (e.g. a git-url dependency version) never unbalances the count.
+    fn matching_brace_end(bytes: &[u8], brace_start: usize) -> Option<usize> {
+        let mut depth = 0i32;
+        let mut i = brace_start;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'"' => {
+                    i = Self::skip_json_string(bytes, i);
+                    continue;
+                }
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i + 1);
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Replace the moli-owned block bounded by `start_marker`/`end_marker`
+    /// in `existing` with `block`, preserving everything outside the
+    /// markers; appends a fresh marked block if none is present yet.
+    fn replace_marked_block(existing: &str, start_marker: &str, end_marker: &str, block: &str) -> String {
+        let marked = format!("{}\n{}\n{}", start_marker, block, end_marker);
+
+        let mut lines: Vec<&str> = existing.lines().collect();
+        match (
+            lines.iter().position(|l| l.trim() == start_marker),
+            lines.iter().position(|l| l.trim() == end_marker),
+        ) {
+            (Some(start), Some(end)) if start <= end => {
+                lines.splice(start..=end, marked.lines());
+                format!("{}\n", lines.join("\n"))
+            }
+            _ => format!("{}\n\n{}\n", existing.trim_end(), marked),
+        }
+    }
+
+    /// Wrap the table headers in `headers` (and everything up to the
+    /// next top-level table header or EOF) in moli's marker comments,
+    /// dropping any markers left over from a previous sync first.
+    fn wrap_toml_tables(content: &str, headers: &[&str], start_marker: &str, end_marker: &str) -> String {
+        let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+        lines.retain(|l| l.trim() != start_marker && l.trim() != end_marker);
+
+        let Some(first) = lines.iter().position(|l| headers.contains(&l.trim())) else {
+            return format!("{}\n", lines.join("\n"));
+        };
+
+        let last = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| headers.contains(&l.trim()))
+            .map(|(i, _)| i)
+            .max()
+            .unwrap_or(first);
+
+        let mut end = lines.len();
+        for (idx, line) in lines.iter().enumerate().skip(last + 1) {
+            if line.trim_start().starts_with('[') {
+                end = idx;
+                break;
+            }
+        }
+
+        lines.insert(end, end_marker.to_string());
+        lines.insert(first, start_marker.to_string());
+
+        format!("{}\n", lines.join("\n"))
+    }
+
+    /// Wrap the single line starting with `needle` in moli's marker
+    /// comments, dropping any markers left over from a previous sync.
+    fn wrap_toml_line(content: &str, needle: &str, start_marker: &str, end_marker: &str) -> String {
+        let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+        lines.retain(|l| l.trim() != start_marker && l.trim() != end_marker);
+
+        if let Some(idx) = lines.iter().position(|l| l.trim_start().starts_with(needle)) {
+            lines.insert(idx + 1, end_marker.to_string());
+            lines.insert(idx, start_marker.to_string());
+        }
+
+        format!("{}\n", lines.join("\n"))
+    }
+
+    fn write(path: &Path, content: &str) -> Result<()> {
+        fs::write(path, content).with_context(|| format!("Failed to write manifest: {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn dep(name: &str, version: &str) -> Dependency {
+        Dependency { name: name.to_string(), version: Some(version.to_string()), features: vec![], dev: false }
+    }
+
+    /// A fresh scratch directory, removed on drop.
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(label: &str) -> Self {
+            let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+            let dir = std::env::temp_dir().join(format!("moli-manifest-test-{}-{}", label, nanos));
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_sync_cargo_toml_creates_package_and_deps() {
+        let dir = TempDir::new("cargo-new");
+        ManifestWriter::sync_cargo_toml(&dir.0, "app", &[dep("serde", "1.0")], None).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("Cargo.toml")).unwrap();
+        assert!(content.contains("name = \"app\""));
+        assert!(content.contains("serde = \"1.0\""));
+        assert!(content.contains(&format!("# {}", MARKER_START)));
+        assert!(content.contains(&format!("# {}", MARKER_END)));
+    }
+
+    #[test]
+    fn test_sync_cargo_toml_prunes_removed_dependency() {
+        let dir = TempDir::new("cargo-prune");
+        ManifestWriter::sync_cargo_toml(&dir.0, "app", &[dep("serde", "1.0"), dep("anyhow", "1.0")], None).unwrap();
+        ManifestWriter::sync_cargo_toml(&dir.0, "app", &[dep("serde", "1.0")], None).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("Cargo.toml")).unwrap();
+        assert!(content.contains("serde"));
+        assert!(!content.contains("anyhow"));
+        // Markers aren't duplicated across re-runs.
+        assert_eq!(content.matches(MARKER_START).count(), 1);
+    }
+
+    #[test]
+    fn test_sync_cargo_toml_preserves_hand_edited_sections() {
+        let dir = TempDir::new("cargo-preserve");
+        fs::write(
+            dir.0.join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[profile.release]\nlto = true\n",
+        )
+        .unwrap();
+
+        ManifestWriter::sync_cargo_toml(&dir.0, "app", &[dep("serde", "1.0")], None).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("Cargo.toml")).unwrap();
+        assert!(content.contains("[profile.release]"));
+        assert!(content.contains("lto = true"));
+        assert!(content.contains("serde"));
+    }
+
+    #[test]
+    fn test_sync_package_json_preserves_key_order_and_prunes() {
+        let dir = TempDir::new("package-json");
+        fs::write(
+            dir.0.join("package.json"),
+            "{\n  \"name\": \"app\",\n  \"scripts\": {\n    \"build\": \"tsc\"\n  },\n  \"dependencies\": {\n    \"left-pad\": \"1.0.0\"\n  },\n  \"version\": \"0.1.0\"\n}\n",
+        )
+        .unwrap();
+
+        ManifestWriter::sync_package_json(&dir.0, "app", &[dep("react", "18.0.0")]).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("package.json")).unwrap();
+        assert!(content.contains("react"));
+        assert!(!content.contains("left-pad"));
+        assert!(content.contains("\"scripts\""));
+        // "version" still appears after "scripts" -- untouched keys keep their order.
+        assert!(content.find("\"scripts\"").unwrap() < content.find("\"version\"").unwrap());
+    }
+
+    #[test]
+    fn test_sync_package_json_ignores_key_name_in_unrelated_value() {
+        let dir = TempDir::new("package-json-keywords");
+        fs::write(
+            dir.0.join("package.json"),
+            "{\n  \"name\": \"app\",\n  \"keywords\": [\"utility\", \"dependencies\"],\n  \"dependencies\": {\n    \"left-pad\": \"1.0.0\"\n  }\n}\n",
+        )
+        .unwrap();
+
+        ManifestWriter::sync_package_json(&dir.0, "app", &[dep("react", "18.0.0")]).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("package.json")).unwrap();
+        assert!(content.contains("\"keywords\": [\"utility\", \"dependencies\"]"));
+        assert!(content.contains("react"));
+        assert!(!content.contains("left-pad"));
+    }
+
+    #[test]
+    fn test_sync_package_json_tolerates_brace_in_dependency_value() {
+        let dir = TempDir::new("package-json-brace");
+        fs::write(
+            dir.0.join("package.json"),
+            "{\n  \"name\": \"app\",\n  \"dependencies\": {\n    \"pkg\": \"git+https://host/{user}/repo.git\"\n  }\n}\n",
+        )
+        .unwrap();
+
+        ManifestWriter::sync_package_json(&dir.0, "app", &[dep("react", "18.0.0")]).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("package.json")).unwrap();
+        assert!(content.contains("react"));
+        assert!(!content.contains("pkg"));
+        assert!(content.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_sync_go_mod_replaces_require_block() {
+        let dir = TempDir::new("go-mod");
+        ManifestWriter::sync_go_mod(&dir.0, "example.com/app", &[dep("rsc.io/quote", "v1.5.2")]).unwrap();
+        ManifestWriter::sync_go_mod(&dir.0, "example.com/app", &[]).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("go.mod")).unwrap();
+        assert!(!content.contains("rsc.io/quote"));
+        assert_eq!(content.matches(MARKER_START).count(), 1);
+    }
+
+    #[test]
+    fn test_sync_pyproject_toml_replaces_dependency_list() {
+        let dir = TempDir::new("pyproject");
+        ManifestWriter::sync_pyproject_toml(&dir.0, "app", &[dep("requests", ">=2.0")]).unwrap();
+        ManifestWriter::sync_pyproject_toml(&dir.0, "app", &[dep("httpx", ">=0.20")]).unwrap();
+
+        let content = fs::read_to_string(dir.0.join("pyproject.toml")).unwrap();
+        assert!(content.contains("httpx"));
+        assert!(!content.contains("requests"));
+        assert_eq!(content.matches(MARKER_START).count(), 1);
+    }
+}
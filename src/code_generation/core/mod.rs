@@ -2,6 +2,10 @@
 pub mod generator;
 pub mod file_builder;
 pub mod directory_builder;
+pub mod template_engine;
+pub mod manifest;
+pub mod diff;
+pub mod context;
 // end auto exported by moli.
 
 // Re-exports for convenience
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use minijinja::{context, Environment};
+
+/// Built-in per-language starter content, used when a `CodeFile` does
+/// not declare its own `template`.
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    ("rust", "fn main() {\n}\n"),
+    ("go", "package main\n\nfunc main() {\n}\n"),
+    ("python", "\"\"\"{{ filename_with_extension }}\"\"\"\n"),
+    ("typescript", "export function {{ module }}() {}\n"),
+    ("javascript", "export function {{ module }}() {}\n"),
+];
+
+/// The values a template is rendered with.
+pub struct RenderContext<'a> {
+    pub project_name: &'a str,
+    pub lang: &'a str,
+    pub module_path: &'a str,
+    pub filename_with_extension: &'a str,
+    pub pub_setting: Option<&'a str>,
+    pub vars: &'a HashMap<String, String>,
+}
+
+/// Renders starter content for newly created code files through an
+/// embedded minijinja engine.
+///
+/// Resolution order for `template`: a `.moli/templates/<name>.jinja`
+/// file relative to the current directory, then `name` itself treated
+/// as an inline path, then (for both cases, and whenever `template` is
+/// absent) the built-in default for `ctx.lang`.
+pub struct TemplateEngine;
+
+impl TemplateEngine {
+    /// Render starter content for a file. Returns an empty string when
+    /// no template and no built-in default apply to `ctx.lang`.
+    pub fn render(template: Option<&str>, ctx: &RenderContext) -> Result<String> {
+        let source = match template {
+            Some(name) => Self::resolve_named(name, ctx.lang)?,
+            None => Self::builtin_default(ctx.lang),
+        };
+
+        let Some(source) = source else {
+            return Ok(String::new());
+        };
+
+        let mut env = Environment::new();
+        env.add_template("file", &source)
+            .context("Failed to parse template")?;
+
+        let rendered = env
+            .get_template("file")
+            .context("Failed to load template")?
+            .render(context! {
+                project_name => ctx.project_name,
+                lang => ctx.lang,
+                module => ctx.module_path,
+                filename_with_extension => ctx.filename_with_extension,
+                pub_setting => ctx.pub_setting,
+                vars => ctx.vars,
+            })
+            .context("Failed to render template")?;
+
+        Ok(rendered)
+    }
+
+    fn resolve_named(name: &str, lang: &str) -> Result<Option<String>> {
+        let project_template = Path::new(".moli/templates").join(format!("{}.jinja", name));
+        if project_template.exists() {
+            return fs::read_to_string(&project_template)
+                .map(Some)
+                .with_context(|| format!("Failed to read template: {}", project_template.display()));
+        }
+
+        let inline_path = Path::new(name);
+        if inline_path.exists() {
+            return fs::read_to_string(inline_path)
+                .map(Some)
+                .with_context(|| format!("Failed to read template: {}", inline_path.display()));
+        }
+
+        Ok(Self::builtin_default(lang))
+    }
+
+    fn builtin_default(lang: &str) -> Option<String> {
+        BUILTIN_TEMPLATES
+            .iter()
+            .find(|(l, _)| *l == lang)
+            .map(|(_, src)| (*src).to_string())
+    }
+}
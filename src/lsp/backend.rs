@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tower_lsp::jsonrpc::Result as LspResult;
+use tower_lsp::lsp_types::*;
+use tower_lsp::{Client, LanguageServer, LspService, Server};
+use crate::lsp::{completion, diagnostics, hover};
+use crate::project_management::config::ConfigParser;
+use crate::project_management::language::LanguageRegistry;
+use crate::code_generation::core::diff::ConfigDiff;
+
+/// `moli lsp` backend: reuses `ConfigParser`/`ConfigValidator` to turn
+/// a one-shot CLI check into an interactive, incrementally-reparsing
+/// analysis service for moli.yml.
+pub struct Backend {
+    client: Client,
+    documents: Mutex<HashMap<Url, String>>,
+}
+
+impl Backend {
+    pub fn new(client: Client) -> Self {
+        Backend { client, documents: Mutex::new(HashMap::new()) }
+    }
+
+    fn document(&self, uri: &Url) -> String {
+        self.documents.lock().unwrap().get(uri).cloned().unwrap_or_default()
+    }
+
+    async fn publish_diagnostics(&self, uri: Url) {
+        let content = self.document(&uri);
+        let diags = diagnostics::collect(&content);
+        self.client.publish_diagnostics(uri, diags, None).await;
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _: InitializeParams) -> LspResult<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+                completion_provider: Some(CompletionOptions::default()),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec!["moli.previewTree".to_string()],
+                    ..ExecuteCommandOptions::default()
+                }),
+                ..ServerCapabilities::default()
+            },
+            ..InitializeResult::default()
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        self.client.log_message(MessageType::INFO, "moli language server ready").await;
+    }
+
+    async fn shutdown(&self) -> LspResult<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = params.text_document.uri.clone();
+        self.documents.lock().unwrap().insert(uri.clone(), params.text_document.text);
+        self.publish_diagnostics(uri).await;
+    }
+
+    async fn did_change(&self, mut params: DidChangeTextDocumentParams) {
+        let uri = params.text_document.uri.clone();
+        if let Some(change) = params.content_changes.pop() {
+            self.documents.lock().unwrap().insert(uri.clone(), change.text);
+        }
+        self.publish_diagnostics(uri).await;
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents.lock().unwrap().remove(&params.text_document.uri);
+    }
+
+    async fn completion(&self, params: CompletionParams) -> LspResult<Option<CompletionResponse>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        let content = self.document(&uri);
+        Ok(Some(CompletionResponse::Array(completion::complete(&content, position))))
+    }
+
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        let content = self.document(&uri);
+        Ok(hover::hover_at(&content, position))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> LspResult<Option<CodeActionResponse>> {
+        let preview = CodeActionOrCommand::Command(Command {
+            title: "Preview generated tree".to_string(),
+            command: "moli.previewTree".to_string(),
+            arguments: Some(vec![serde_json::json!(params.text_document.uri.to_string())]),
+        });
+        Ok(Some(vec![preview]))
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> LspResult<Option<serde_json::Value>> {
+        if params.command != "moli.previewTree" {
+            return Ok(None);
+        }
+
+        let uri = params.arguments.first().and_then(|arg| arg.as_str()).and_then(|s| Url::parse(s).ok());
+        let Some(uri) = uri else { return Ok(None) };
+
+        let content = self.document(&uri);
+        let Ok(config) = ConfigParser::parse_string(&content) else { return Ok(None) };
+        let registry = LanguageRegistry::load(config.languages());
+        let files = ConfigDiff::preview(&config, &registry);
+
+        self.client
+            .show_message(MessageType::INFO, format!("moli up would generate {} file(s)", files.len()))
+            .await;
+
+        Ok(Some(serde_json::json!({ "files": files })))
+    }
+}
+
+/// Run the `moli lsp` server over stdio until the client disconnects.
+pub async fn run() {
+    let stdin = tokio::io::stdin();
+    let stdout = tokio::io::stdout();
+
+    let (service, socket) = LspService::new(Backend::new);
+    Server::new(stdin, stdout, socket).serve(service).await;
+}
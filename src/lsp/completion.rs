@@ -0,0 +1,35 @@
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, Position};
+use crate::project_management::language::LanguageRegistry;
+
+/// Field keys valid at the project/module/file level.
+const FIELD_KEYS: &[&str] =
+    &["name", "root", "lang", "spec", "tree", "file", "pub", "vars", "template", "deps"];
+
+/// Completions for the line at `position`: language values after
+/// `lang:`, field keys otherwise.
+pub fn complete(content: &str, position: Position) -> Vec<CompletionItem> {
+    let Some(line) = content.lines().nth(position.line as usize) else { return Vec::new() };
+    let prefix = &line[..(position.character as usize).min(line.len())];
+
+    if prefix.trim_start().starts_with("lang:") {
+        let registry = LanguageRegistry::load(&[]);
+        return registry
+            .names()
+            .into_iter()
+            .map(|name| CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::VALUE),
+                ..CompletionItem::default()
+            })
+            .collect();
+    }
+
+    FIELD_KEYS
+        .iter()
+        .map(|key| CompletionItem {
+            label: format!("{}:", key),
+            kind: Some(CompletionItemKind::FIELD),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use crate::project_management::config::{ConfigParser, ConfigValidator};
+use crate::project_management::language::LanguageRegistry;
+
+/// Diagnostics for a moli.yml document.
+///
+/// Spans are computed by scanning raw lines rather than tracking
+/// positions through serde_yaml, which is precise enough for
+/// moli.yml's flat, line-per-field style and avoids a hand-rolled
+/// position-tracking YAML parser.
+pub fn collect(content: &str) -> Vec<Diagnostic> {
+    let config = match ConfigParser::parse_string(content) {
+        Ok(config) => config,
+        Err(err) => return vec![at(0, format!("{err:#}"))],
+    };
+
+    let registry = LanguageRegistry::load(config.languages());
+    let mut diagnostics = line_scan(content, &registry);
+
+    // The line scan catches the common single-line mistakes; anything
+    // else (e.g. an empty module) falls through to the validator,
+    // reported at the top of the document.
+    if diagnostics.is_empty() {
+        if let Err(err) = ConfigValidator::validate(&config, &registry) {
+            diagnostics.push(at(0, format!("{err:#}")));
+        }
+    }
+
+    diagnostics
+}
+
+fn line_scan(content: &str, registry: &LanguageRegistry) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut seen_names: HashMap<String, usize> = HashMap::new();
+    let mut root_lines = Vec::new();
+
+    // moli.yml projects are a flat list of `- name: ...` entries; every
+    // project-level field (`root:`, `lang:`, `name:`) lines up at the
+    // same two indentations throughout the file (the dash, and the
+    // dash's column + 2). Nested `tree:`/`file:`/`vars:` entries are
+    // always indented further, so anchoring on the first project's
+    // indentation keeps this scan from wandering into module/file names
+    // or `vars:` maps that happen to reuse the same keys. A top-level
+    // `languages:` key (the other entry in the mapping format) is
+    // tracked separately so its own `- name: ...` entries never get
+    // mistaken for projects.
+    let mut project_indent = None;
+    let mut in_languages_section = false;
+
+    for (line_no, line) in content.lines().enumerate() {
+        let indent = line.len() - line.trim_start().len();
+        let trimmed = line.trim_start();
+
+        if indent == 0 {
+            if trimmed.trim_end() == "languages:" {
+                in_languages_section = true;
+                continue;
+            }
+            if trimmed.trim_end() == "projects:" {
+                in_languages_section = false;
+                continue;
+            }
+            if !trimmed.is_empty() && !trimmed.starts_with("- ") {
+                // Some other top-level key (shouldn't happen in a valid
+                // moli.yml, but don't let it leak into the languages
+                // section's indentation range). A blank line, though,
+                // is just spacing between entries and shouldn't end the
+                // section.
+                in_languages_section = false;
+            }
+        }
+
+        if in_languages_section {
+            continue;
+        }
+
+        if let Some(name) = trimmed.strip_prefix("- name: ") {
+            let project_indent = *project_indent.get_or_insert(indent);
+            if indent != project_indent {
+                continue;
+            }
+
+            let name = name.trim().to_string();
+            let count = seen_names.entry(name.clone()).or_insert(0);
+            *count += 1;
+            if *count > 1 {
+                diagnostics.push(at(line_no, format!("Duplicate project name: {}", name)));
+            }
+            continue;
+        }
+
+        let Some(project_indent) = project_indent else { continue };
+        let field_indent = project_indent + 2;
+        if indent != field_indent {
+            continue;
+        }
+
+        if trimmed.trim_end() == "root: true" {
+            root_lines.push(line_no);
+        }
+
+        if let Some(lang) = trimmed.strip_prefix("lang: ") {
+            let lang = lang.trim();
+            if !registry.is_known(lang) {
+                diagnostics.push(at(
+                    line_no,
+                    format!("Unknown language '{}'. Known languages: {}", lang, registry.names().join(", ")),
+                ));
+            }
+        }
+    }
+
+    for line_no in root_lines.iter().skip(1) {
+        diagnostics.push(at(*line_no, "Only one project may have 'root: true'".to_string()));
+    }
+
+    diagnostics
+}
+
+fn at(line: usize, message: String) -> Diagnostic {
+    let range = Range::new(Position::new(line as u32, 0), Position::new(line as u32, u32::MAX));
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("moli".to_string()),
+        message,
+        ..Diagnostic::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_line_inside_languages_section_does_not_leak_into_projects() {
+        let yaml_content = r#"
+languages:
+- name: kotlin
+  extension: kt
+  main_file: Main
+
+- name: swift
+  extension: swift
+  main_file: main
+projects:
+  - name: backend
+    lang: bogus
+    spec:
+      - name: src
+        file:
+          - name: main
+  - name: backend
+    lang: rust
+    spec:
+      - name: src
+        file:
+          - name: main
+"#;
+
+        let diagnostics = collect(yaml_content);
+
+        // The blank line between the two `languages:` entries must not
+        // be mistaken for the end of the section -- if it were, the
+        // unindented `- name: swift` language entry would be wrongly
+        // treated as the first "project", throwing off every later
+        // project's indent check and silently dropping the
+        // duplicate-name and unknown-language diagnostics below.
+        assert!(
+            diagnostics.iter().any(|d| d.message.contains("Duplicate project name: backend")),
+            "duplicate project name must still be detected: {diagnostics:?}"
+        );
+        assert!(
+            diagnostics.iter().any(|d| d.message.contains("Unknown language 'bogus'")),
+            "unknown project language must still be detected: {diagnostics:?}"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkedString, Position};
+
+/// Hover text for each moli.yml field, mirroring the doc comments on
+/// `Project`/`Module`/`CodeFile`.
+const FIELD_DOCS: &[(&str, &str)] = &[
+    ("name", "Project, module, or file name. For files without a '.', the language's extension is appended."),
+    ("root", "Marks this project as the one generated directly into the current directory."),
+    ("lang", "Programming language for this project, resolved against the LanguageRegistry."),
+    ("spec", "Top-level modules (recursive directory structure) for a project."),
+    ("tree", "Nested sub-modules (recursive directory structure) for a module."),
+    ("file", "Code files to generate in this project or module."),
+    ("pub", "Visibility setting applied to this module's generated items."),
+    ("vars", "Template variables, inherited by nested modules/files and merged with their own."),
+    ("template", "Named template (.moli/templates/<name>.jinja) or inline path to render starter content from."),
+    ("deps", "Dependencies synthesized into the project's manifest on 'moli up'."),
+    ("languages", "Custom LanguageDef entries registered alongside the built-in defaults."),
+];
+
+/// Hover text for the field named on the line at `position`, if any.
+pub fn hover_at(content: &str, position: Position) -> Option<Hover> {
+    let line = content.lines().nth(position.line as usize)?;
+    let key = line.trim_start().trim_start_matches("- ").split(':').next()?.trim();
+
+    let (_, doc) = FIELD_DOCS.iter().find(|(field, _)| *field == key)?;
+
+    Some(Hover { contents: HoverContents::Scalar(MarkedString::String((*doc).to_string())), range: None })
+}
@@ -0,0 +1,8 @@
+// start auto exported by moli.
+pub mod backend;
+pub mod diagnostics;
+pub mod completion;
+pub mod hover;
+// end auto exported by moli.
+
+pub use backend::run;
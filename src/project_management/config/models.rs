@@ -1,10 +1,16 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
+use crate::project_management::language::{LanguageDef, LanguageRegistry};
 
 /// v2 moli.yml configuration root
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MoliConfig {
     #[serde(rename = "$value")]
     pub projects: Vec<Project>,
+    /// Extra languages declared in a `languages:` section, merged into
+    /// the `LanguageRegistry` alongside the built-in defaults.
+    #[serde(default)]
+    pub languages: Vec<LanguageDef>,
 }
 
 /// Individual project configuration
@@ -18,6 +24,47 @@ pub struct Project {
     pub file: Vec<CodeFile>,
     #[serde(default)]
     pub spec: Vec<Module>,
+    /// Variables inherited by every module and file in this project,
+    /// available to templates as `vars.<key>`.
+    #[serde(default)]
+    pub vars: HashMap<String, String>,
+    /// Dependencies to synthesize into the project's manifest
+    /// (Cargo.toml/package.json/go.mod/pyproject.toml) on `moli up`.
+    #[serde(default)]
+    pub deps: Vec<Dependency>,
+}
+
+/// A single dependency declared in `moli.yml`, merged into the
+/// project's language-specific manifest without disturbing
+/// user-added entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    /// Version requirement (Cargo `version`, npm semver range, Go
+    /// module version, PEP 508 specifier). When omitted, Cargo and npm
+    /// get `*`, Python gets the bare package name, and Go gets a
+    /// `v0.0.0` placeholder.
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Cargo feature flags; ignored for non-Rust languages.
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Place under `[dev-dependencies]` instead of `[dependencies]`
+    /// (Rust only).
+    #[serde(default)]
+    pub dev: bool,
+}
+
+impl Dependency {
+    /// Get the dependency's package name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the declared version requirement, if any
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
 }
 
 /// Module or directory structure
@@ -30,6 +77,10 @@ pub struct Module {
     pub tree: Vec<Module>,
     #[serde(default)]
     pub file: Vec<CodeFile>,
+    /// Variables inherited by this module's subtree and files, merged
+    /// over the parent project's/module's `vars`.
+    #[serde(default)]
+    pub vars: HashMap<String, String>,
 }
 
 /// Individual code file
@@ -38,6 +89,15 @@ pub struct CodeFile {
     pub name: String,
     #[serde(default)]
     pub r#pub: Option<String>,
+    /// A named template (resolved against `.moli/templates/`) or an
+    /// inline path to render starter content from. When absent, the
+    /// built-in default for the project's language is used, if any.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Variables passed to the template, merged over inherited
+    /// project/module `vars` (this file's entries win on conflict).
+    #[serde(default)]
+    pub vars: HashMap<String, String>,
 }
 
 impl MoliConfig {
@@ -60,6 +120,11 @@ impl MoliConfig {
     pub fn is_single_project(&self) -> bool {
         self.root_project().is_some()
     }
+
+    /// Get languages declared inline in this config's `languages:` section
+    pub fn languages(&self) -> &[LanguageDef] {
+        &self.languages
+    }
 }
 
 impl Project {
@@ -87,6 +152,16 @@ impl Project {
     pub fn files(&self) -> &[CodeFile] {
         &self.file
     }
+
+    /// Get this project's inheritable template variables
+    pub fn vars(&self) -> &HashMap<String, String> {
+        &self.vars
+    }
+
+    /// Get this project's declared dependencies
+    pub fn deps(&self) -> &[Dependency] {
+        &self.deps
+    }
 }
 
 impl Module {
@@ -119,6 +194,11 @@ impl Module {
     pub fn pub_setting(&self) -> Option<&str> {
         self.r#pub.as_deref()
     }
+
+    /// Get this module's inheritable template variables
+    pub fn vars(&self) -> &HashMap<String, String> {
+        &self.vars
+    }
 }
 
 impl CodeFile {
@@ -127,22 +207,13 @@ impl CodeFile {
         &self.name
     }
 
-    /// Get file name with extension based on language
-    pub fn filename_with_extension(&self, language: &str) -> String {
+    /// Get file name with extension, resolved from `registry` for `language`
+    pub fn filename_with_extension(&self, registry: &LanguageRegistry, language: &str) -> String {
         if self.name.contains('.') {
             // Already has extension
             self.name.clone()
         } else {
-            // Add language-specific extension
-            let extension = match language {
-                "rust" => "rs",
-                "go" => "go",
-                "python" => "py",
-                "javascript" => "js",
-                "typescript" => "ts",
-                "markdown" => "md",
-                _ => "txt", // fallback
-            };
+            let extension = registry.get(language).map(|lang| lang.extension.as_str()).unwrap_or("txt");
             format!("{}.{}", self.name, extension)
         }
     }
@@ -151,4 +222,14 @@ impl CodeFile {
     pub fn pub_setting(&self) -> Option<&str> {
         self.r#pub.as_deref()
     }
+
+    /// Get the named/inline template to render starter content from
+    pub fn template(&self) -> Option<&str> {
+        self.template.as_deref()
+    }
+
+    /// Get this file's own template variables
+    pub fn vars(&self) -> &HashMap<String, String> {
+        &self.vars
+    }
 }
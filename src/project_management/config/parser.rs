@@ -1,7 +1,18 @@
 use std::fs;
 use std::path::Path;
 use anyhow::{Context, Result};
-use crate::project_management::config::models::MoliConfig;
+use serde::Deserialize;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::project_management::language::LanguageDef;
+
+/// moli.yml with an optional `languages:` section alongside the
+/// project list, for registering custom `LanguageDef`s inline.
+#[derive(Debug, Deserialize)]
+struct ConfigDocument {
+    #[serde(default)]
+    languages: Vec<LanguageDef>,
+    projects: Vec<Project>,
+}
 
 /// Config parser for v2 moli.yml format
 pub struct ConfigParser;
@@ -16,12 +27,19 @@ impl ConfigParser {
     }
 
     /// Parse moli.yml from string content
+    ///
+    /// Accepts the classic format (a bare YAML list of projects) as
+    /// well as a mapping with `languages:` and `projects:` keys, for
+    /// projects that need to register custom languages.
     pub fn parse_string(content: &str) -> Result<MoliConfig> {
-        let projects: Vec<crate::project_management::config::models::Project> =
-            serde_yaml::from_str(content)
-                .with_context(|| "Failed to parse YAML content")?;
+        if let Ok(doc) = serde_yaml::from_str::<ConfigDocument>(content) {
+            return Ok(MoliConfig { projects: doc.projects, languages: doc.languages });
+        }
+
+        let projects: Vec<Project> =
+            serde_yaml::from_str(content).with_context(|| "Failed to parse YAML content")?;
 
-        Ok(MoliConfig { projects })
+        Ok(MoliConfig { projects, languages: Vec::new() })
     }
 
     /// Parse default moli.yml in current directory
@@ -43,6 +61,7 @@ impl ConfigParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::project_management::language::LanguageRegistry;
 
     #[test]
     fn test_parse_single_project() {
@@ -120,11 +139,12 @@ mod tests {
         let config = ConfigParser::parse_string(yaml_content).unwrap();
         let project = &config.projects()[0];
         let files = &project.spec()[0].files();
+        let registry = LanguageRegistry::default();
 
         // Test auto extension
-        assert_eq!(files[0].filename_with_extension("rust"), "model.rs");
+        assert_eq!(files[0].filename_with_extension(&registry, "rust"), "model.rs");
 
         // Test explicit extension
-        assert_eq!(files[1].filename_with_extension("rust"), "component.vue");
+        assert_eq!(files[1].filename_with_extension(&registry, "rust"), "component.vue");
     }
 }
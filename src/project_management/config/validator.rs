@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+use anyhow::{bail, Result};
+use crate::project_management::config::models::{Module, MoliConfig};
+use crate::project_management::language::LanguageRegistry;
+
+/// Validates a parsed `MoliConfig` for structural and semantic
+/// correctness before generation runs.
+pub struct ConfigValidator;
+
+impl ConfigValidator {
+    /// Validate a full moli configuration against `registry`.
+    pub fn validate(config: &MoliConfig, registry: &LanguageRegistry) -> Result<()> {
+        if config.projects().is_empty() {
+            bail!("moli.yml must declare at least one project");
+        }
+
+        let mut names = HashSet::new();
+        let mut root_count = 0;
+
+        for project in config.projects() {
+            if !names.insert(project.name()) {
+                bail!("Duplicate project name: {}", project.name());
+            }
+
+            if project.is_root() {
+                root_count += 1;
+            }
+
+            if !registry.is_known(project.language()) {
+                bail!(
+                    "Unsupported language '{}' for project '{}'. Supported languages: {}",
+                    project.language(),
+                    project.name(),
+                    registry.names().join(", ")
+                );
+            }
+
+            Self::validate_modules(project.spec())?;
+        }
+
+        if root_count > 1 {
+            bail!("Only one project may have 'root: true', found {}", root_count);
+        }
+
+        Ok(())
+    }
+
+    fn validate_modules(modules: &[Module]) -> Result<()> {
+        for module in modules {
+            if !module.has_files() && !module.has_subtree() {
+                bail!("Module '{}' has no files and no subtree", module.name());
+            }
+            Self::validate_modules(module.subtree())?;
+        }
+        Ok(())
+    }
+}
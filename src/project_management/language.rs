@@ -0,0 +1,338 @@
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+
+/// Directory-layout convention for a language: files live alongside
+/// the project root, or nested under a `tree`-style source directory
+/// (Rust's `src/`, for example).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DirectoryConvention {
+    Root,
+    Tree,
+}
+
+/// Everything moli needs to know about a language in order to
+/// generate for it, previously scattered across hardcoded tables in
+/// `new`, `filename_with_extension`, and the barrel-file generator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageDef {
+    /// Canonical name, as written in `moli.yml`'s `lang` field.
+    pub name: String,
+    /// Alternate spellings accepted anywhere `name` is accepted.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Source file extension (without the leading dot).
+    pub extension: String,
+    /// Main file name for a root project (e.g. `main` for Rust/Go).
+    pub main_file: String,
+    /// Main file name for a non-root project, if different (e.g.
+    /// `lib` for a Rust library crate). Defaults to `main_file`.
+    #[serde(default)]
+    pub lib_file: Option<String>,
+    /// Root-level files vs. a nested `src/`-style `tree`.
+    #[serde(default = "default_convention")]
+    pub convention: DirectoryConvention,
+    /// Filenames treated as moli-managed: never templated, created
+    /// empty. Of these, only `barrel_file` has its marker section
+    /// rewritten during generation.
+    #[serde(default)]
+    pub managed_files: Vec<String>,
+    /// The managed filename whose marker section lists child modules
+    /// for a directory (e.g. `mod.rs`); `None` for languages with no
+    /// barrel convention.
+    #[serde(default)]
+    pub barrel_file: Option<String>,
+    /// Export statement for a child module, with `{name}` substituted
+    /// for the child's name (e.g. `pub mod {name};`).
+    #[serde(default)]
+    pub export_line_template: String,
+    /// Comment syntax prefix used for the
+    /// `<prefix> start auto exported by moli.` markers.
+    #[serde(default = "default_comment_prefix")]
+    pub comment_prefix: String,
+}
+
+fn default_convention() -> DirectoryConvention {
+    DirectoryConvention::Root
+}
+
+fn default_comment_prefix() -> String {
+    "//".to_string()
+}
+
+impl LanguageDef {
+    /// Whether `name` refers to this language, by canonical name or alias.
+    pub fn matches(&self, name: &str) -> bool {
+        self.name == name || self.aliases.iter().any(|alias| alias == name)
+    }
+
+    /// The main file name for a root or non-root project.
+    pub fn main_file_name(&self, is_root: bool) -> &str {
+        if is_root {
+            &self.main_file
+        } else {
+            self.lib_file.as_deref().unwrap_or(&self.main_file)
+        }
+    }
+
+    /// Whether `filename` is moli-managed for this language.
+    pub fn is_managed(&self, filename: &str) -> bool {
+        self.managed_files.iter().any(|managed| managed == filename)
+    }
+
+    /// Render the export line for a child module named `child_name`.
+    pub fn export_line(&self, child_name: &str) -> String {
+        self.export_line_template.replace("{name}", child_name)
+    }
+
+    pub fn marker_start(&self) -> String {
+        format!("{} start auto exported by moli.", self.comment_prefix)
+    }
+
+    pub fn marker_end(&self) -> String {
+        format!("{} end auto exported by moli.", self.comment_prefix)
+    }
+}
+
+/// Registry of known languages, seeded with moli's built-in defaults
+/// and extendable via `~/.config/moli/languages.yml` and/or a
+/// `languages:` section in `moli.yml`, so new languages (kotlin, ruby,
+/// zig, ...) can be registered without code changes.
+#[derive(Debug, Clone)]
+pub struct LanguageRegistry {
+    languages: Vec<LanguageDef>,
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        LanguageRegistry { languages: Self::builtins() }
+    }
+}
+
+impl LanguageRegistry {
+    fn builtins() -> Vec<LanguageDef> {
+        vec![
+            LanguageDef {
+                name: "rust".into(),
+                aliases: vec![],
+                extension: "rs".into(),
+                main_file: "main".into(),
+                lib_file: Some("lib".into()),
+                convention: DirectoryConvention::Tree,
+                managed_files: vec!["mod.rs".into(), "lib.rs".into(), "main.rs".into()],
+                barrel_file: Some("mod.rs".into()),
+                export_line_template: "pub mod {name};".into(),
+                comment_prefix: "//".into(),
+            },
+            LanguageDef {
+                name: "go".into(),
+                aliases: vec![],
+                extension: "go".into(),
+                main_file: "main".into(),
+                lib_file: None,
+                convention: DirectoryConvention::Root,
+                managed_files: vec![],
+                barrel_file: None,
+                export_line_template: String::new(),
+                comment_prefix: "//".into(),
+            },
+            LanguageDef {
+                name: "python".into(),
+                aliases: vec!["py".into()],
+                extension: "py".into(),
+                main_file: "main".into(),
+                lib_file: None,
+                convention: DirectoryConvention::Tree,
+                managed_files: vec!["__init__.py".into()],
+                barrel_file: Some("__init__.py".into()),
+                export_line_template: "from . import {name}".into(),
+                comment_prefix: "#".into(),
+            },
+            LanguageDef {
+                name: "typescript".into(),
+                aliases: vec!["ts".into()],
+                extension: "ts".into(),
+                main_file: "index".into(),
+                lib_file: None,
+                convention: DirectoryConvention::Tree,
+                managed_files: vec!["index.ts".into()],
+                barrel_file: Some("index.ts".into()),
+                export_line_template: "export * from './{name}';".into(),
+                comment_prefix: "//".into(),
+            },
+            LanguageDef {
+                name: "javascript".into(),
+                aliases: vec!["js".into()],
+                extension: "js".into(),
+                main_file: "index".into(),
+                lib_file: None,
+                convention: DirectoryConvention::Tree,
+                managed_files: vec!["index.js".into()],
+                barrel_file: Some("index.js".into()),
+                export_line_template: "export * from './{name}';".into(),
+                comment_prefix: "//".into(),
+            },
+            LanguageDef {
+                name: "any".into(),
+                aliases: vec![],
+                extension: "txt".into(),
+                main_file: "README.md".into(),
+                lib_file: None,
+                convention: DirectoryConvention::Root,
+                managed_files: vec![],
+                barrel_file: None,
+                export_line_template: String::new(),
+                comment_prefix: "#".into(),
+            },
+        ]
+    }
+
+    /// Load the built-in registry merged with
+    /// `~/.config/moli/languages.yml` (if present) and `extra`
+    /// languages declared inline in `moli.yml`. Later sources override
+    /// earlier ones by canonical name.
+    pub fn load(extra: &[LanguageDef]) -> Self {
+        let mut registry = Self::default();
+
+        if let Some(path) = Self::user_config_path() {
+            if let Ok(content) = fs::read_to_string(&path) {
+                if let Ok(defs) = serde_yaml::from_str::<Vec<LanguageDef>>(&content) {
+                    registry.register_all(defs);
+                }
+            }
+        }
+
+        registry.register_all(extra.to_vec());
+        registry
+    }
+
+    fn user_config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("moli").join("languages.yml"))
+    }
+
+    /// Register languages, overriding any existing entry with the same
+    /// canonical name.
+    pub fn register_all(&mut self, defs: Vec<LanguageDef>) {
+        for def in defs {
+            match self.languages.iter_mut().find(|existing| existing.name == def.name) {
+                Some(existing) => *existing = def,
+                None => self.languages.push(def),
+            }
+        }
+    }
+
+    /// Find a language definition by canonical name or alias.
+    pub fn get(&self, name: &str) -> Option<&LanguageDef> {
+        self.languages.iter().find(|lang| lang.matches(name))
+    }
+
+    /// Check whether `name` resolves to a known language.
+    pub fn is_known(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Canonical names of every registered language, for prompts and
+    /// error messages.
+    pub fn names(&self) -> Vec<&str> {
+        self.languages.iter().map(|lang| lang.name.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_registry_knows_builtins() {
+        let registry = LanguageRegistry::default();
+
+        assert!(registry.is_known("rust"));
+        assert!(registry.is_known("py"));
+        assert!(!registry.is_known("kotlin"));
+    }
+
+    #[test]
+    fn test_matches_by_alias() {
+        let registry = LanguageRegistry::default();
+
+        let python = registry.get("py").unwrap();
+        assert_eq!(python.name, "python");
+    }
+
+    #[test]
+    fn test_register_all_overrides_by_name() {
+        let mut registry = LanguageRegistry::default();
+
+        registry.register_all(vec![LanguageDef {
+            name: "rust".into(),
+            aliases: vec![],
+            extension: "rs".into(),
+            main_file: "entry".into(),
+            lib_file: None,
+            convention: DirectoryConvention::Root,
+            managed_files: vec![],
+            barrel_file: None,
+            export_line_template: String::new(),
+            comment_prefix: "//".into(),
+        }]);
+
+        assert_eq!(registry.get("rust").unwrap().main_file, "entry");
+        assert_eq!(registry.names().len(), LanguageRegistry::builtins().len());
+    }
+
+    #[test]
+    fn test_register_all_adds_new_language() {
+        let mut registry = LanguageRegistry::default();
+
+        registry.register_all(vec![LanguageDef {
+            name: "zig".into(),
+            aliases: vec![],
+            extension: "zig".into(),
+            main_file: "main".into(),
+            lib_file: None,
+            convention: DirectoryConvention::Root,
+            managed_files: vec![],
+            barrel_file: None,
+            export_line_template: String::new(),
+            comment_prefix: "//".into(),
+        }]);
+
+        assert!(registry.is_known("zig"));
+    }
+
+    #[test]
+    fn test_main_file_name_falls_back_to_main_file() {
+        let registry = LanguageRegistry::default();
+        let go = registry.get("go").unwrap();
+
+        assert_eq!(go.main_file_name(true), "main");
+        assert_eq!(go.main_file_name(false), "main");
+    }
+
+    #[test]
+    fn test_main_file_name_uses_lib_file_for_non_root() {
+        let registry = LanguageRegistry::default();
+        let rust = registry.get("rust").unwrap();
+
+        assert_eq!(rust.main_file_name(true), "main");
+        assert_eq!(rust.main_file_name(false), "lib");
+    }
+
+    #[test]
+    fn test_export_line_substitutes_name() {
+        let registry = LanguageRegistry::default();
+        let rust = registry.get("rust").unwrap();
+
+        assert_eq!(rust.export_line("domain"), "pub mod domain;");
+    }
+
+    #[test]
+    fn test_markers_use_comment_prefix() {
+        let registry = LanguageRegistry::default();
+        let python = registry.get("python").unwrap();
+
+        assert_eq!(python.marker_start(), "# start auto exported by moli.");
+        assert_eq!(python.marker_end(), "# end auto exported by moli.");
+    }
+}